@@ -1,81 +1,745 @@
 use halo2_proofs::{
     arithmetic::FieldExt,
-    circuit::{AssignedCell, Layouter, SimpleFloorPlanner, Value},
-    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Instance, Selector},
-    poly::Rotation,
+    circuit::{AssignedCell, Chip, Layouter, SimpleFloorPlanner, Value},
     dev::MockProver,
     pasta::Fp,
+    plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Instance, Selector, TableColumn},
+    poly::Rotation,
 };
 use std::marker::PhantomData;
 
+mod prove_and_verify;
+
+// ANCHOR: instructions
+/// A variable representing a number.
+#[derive(Clone)]
+struct Number<F: FieldExt>(AssignedCell<F, F>);
+
+trait NumericInstructions<F: FieldExt>: Chip<F> {
+    /// Variable representing a number.
+    type Num;
+
+    /// Loads a number into the circuit as a private input.
+    fn load_private(&self, layouter: impl Layouter<F>, value: Value<F>)
+        -> Result<Self::Num, Error>;
+
+    /// Loads a number into the circuit as a fixed constant.
+    fn load_constant(&self, layouter: impl Layouter<F>, constant: F) -> Result<Self::Num, Error>;
+
+    /// Returns `a * b`.
+    fn mul(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+    ) -> Result<Self::Num, Error>;
+
+    /// Exposes a number as a public input to the circuit.
+    fn expose_public(
+        &self,
+        layouter: impl Layouter<F>,
+        num: Self::Num,
+        row: usize,
+    ) -> Result<(), Error>;
+}
+// ANCHOR_END: instructions
+
+/// Instructions for adding two numbers.
+trait AddInstructions<F: FieldExt>: Chip<F> {
+    /// Variable representing a number.
+    type Num;
+
+    /// Returns `a + b`.
+    fn add(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+    ) -> Result<Self::Num, Error>;
+}
+
+/// Instructions for multiplying two numbers.
+trait MulInstructions<F: FieldExt>: Chip<F> {
+    /// Variable representing a number.
+    type Num;
+
+    /// Returns `a * b`.
+    fn mul(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+    ) -> Result<Self::Num, Error>;
+}
+
+/// The full set of instructions needed to compose the addition and
+/// multiplication chips into a single field gadget.
+trait FieldInstructions<F: FieldExt>:
+    AddInstructions<F, Num = <Self as FieldInstructions<F>>::Num>
+    + MulInstructions<F, Num = <Self as FieldInstructions<F>>::Num>
+{
+    /// Variable representing a number.
+    type Num;
+
+    /// Loads a number into the circuit as a private input.
+    fn load_private(
+        &self,
+        layouter: impl Layouter<F>,
+        value: Value<F>,
+    ) -> Result<<Self as FieldInstructions<F>>::Num, Error>;
+
+    /// Exposes a number as a public input to the circuit.
+    fn expose_public(
+        &self,
+        layouter: impl Layouter<F>,
+        num: <Self as FieldInstructions<F>>::Num,
+        row: usize,
+    ) -> Result<(), Error>;
+}
+
+// Define the chip for our addition circuit
+struct AdditionChip<F: FieldExt> {
+    config: AdditionConfig,
+    _marker: PhantomData<F>,
+}
+
+// Configuration for our addition chip
+#[derive(Clone, Debug)]
+struct AdditionConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    c: Column<Advice>,
+    s_add: Selector,
+}
+
+// Implementation of the addition chip
+impl<F: FieldExt> AdditionChip<F> {
+    fn construct(config: AdditionConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>, advice: [Column<Advice>; 3]) -> AdditionConfig {
+        let [a, b, c] = advice;
+        let s_add = meta.selector();
+
+        meta.create_gate("addition", |meta| {
+            let s = meta.query_selector(s_add);
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            let c = meta.query_advice(c, Rotation::cur());
+            vec![s * (a + b - c)]
+        });
+
+        AdditionConfig { a, b, c, s_add }
+    }
+}
+
+impl<F: FieldExt> Chip<F> for AdditionChip<F> {
+    type Config = AdditionConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<F: FieldExt> AddInstructions<F> for AdditionChip<F> {
+    type Num = Number<F>;
+
+    fn add(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+    ) -> Result<Self::Num, Error> {
+        let config = self.config();
+
+        layouter.assign_region(
+            || "addition",
+            |mut region| {
+                config.s_add.enable(&mut region, 0)?;
+
+                a.0.copy_advice(|| "a", &mut region, config.a, 0)?;
+                b.0.copy_advice(|| "b", &mut region, config.b, 0)?;
+
+                let value = a.0.value().copied() + b.0.value();
+
+                region
+                    .assign_advice(|| "a + b", config.c, 0, || value)
+                    .map(Number)
+            },
+        )
+    }
+}
+
 // Define the chip for our multiplication circuit
 struct MultiplicationChip<F: FieldExt> {
     config: MultiplicationConfig,
     _marker: PhantomData<F>,
 }
 
-// Configuration for our multiplication chip
+// Configuration for our multiplication chip
+#[derive(Clone, Debug)]
+struct MultiplicationConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    c: Column<Advice>,
+    s_mul: Selector,
+    instance: Column<Instance>,
+}
+
+// Implementation of the multiplication chip
+impl<F: FieldExt> MultiplicationChip<F> {
+    fn construct(config: MultiplicationConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    fn configure(
+        meta: &mut ConstraintSystem<F>,
+        advice: [Column<Advice>; 3],
+        instance: Column<Instance>,
+    ) -> MultiplicationConfig {
+        let [a, b, c] = advice;
+        let s_mul = meta.selector();
+        let constant = meta.fixed_column();
+
+        meta.enable_equality(a);
+        meta.enable_equality(b);
+        meta.enable_equality(c);
+        meta.enable_equality(instance);
+        meta.enable_constant(constant);
+
+        meta.create_gate("multiplication", |meta| {
+            let s = meta.query_selector(s_mul);
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            let c = meta.query_advice(c, Rotation::cur());
+            vec![s * (a * b - c)]
+        });
+
+        MultiplicationConfig {
+            a,
+            b,
+            c,
+            s_mul,
+            instance,
+        }
+    }
+}
+
+impl<F: FieldExt> Chip<F> for MultiplicationChip<F> {
+    type Config = MultiplicationConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<F: FieldExt> NumericInstructions<F> for MultiplicationChip<F> {
+    type Num = Number<F>;
+
+    fn load_private(
+        &self,
+        mut layouter: impl Layouter<F>,
+        value: Value<F>,
+    ) -> Result<Self::Num, Error> {
+        let config = self.config();
+
+        layouter.assign_region(
+            || "load private",
+            |mut region| {
+                region
+                    .assign_advice(|| "private input", config.a, 0, || value)
+                    .map(Number)
+            },
+        )
+    }
+
+    fn load_constant(
+        &self,
+        mut layouter: impl Layouter<F>,
+        constant: F,
+    ) -> Result<Self::Num, Error> {
+        let config = self.config();
+
+        layouter.assign_region(
+            || "load constant",
+            |mut region| {
+                region
+                    .assign_advice_from_constant(|| "constant value", config.a, 0, constant)
+                    .map(Number)
+            },
+        )
+    }
+
+    fn mul(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+    ) -> Result<Self::Num, Error> {
+        let config = self.config();
+
+        layouter.assign_region(
+            || "multiplication",
+            |mut region| {
+                config.s_mul.enable(&mut region, 0)?;
+
+                a.0.copy_advice(|| "a", &mut region, config.a, 0)?;
+                b.0.copy_advice(|| "b", &mut region, config.b, 0)?;
+
+                let value = a.0.value().copied() * b.0.value();
+
+                region
+                    .assign_advice(|| "a * b", config.c, 0, || value)
+                    .map(Number)
+            },
+        )
+    }
+
+    fn expose_public(
+        &self,
+        mut layouter: impl Layouter<F>,
+        num: Self::Num,
+        row: usize,
+    ) -> Result<(), Error> {
+        let config = self.config();
+
+        layouter.constrain_instance(num.0.cell(), config.instance, row)
+    }
+}
+
+// Define our circuit
+#[derive(Default)]
+struct MultiplicationCircuit<F: FieldExt> {
+    a: Value<F>,
+    b: Value<F>,
+}
+
+impl<F: FieldExt> Circuit<F> for MultiplicationCircuit<F> {
+    type Config = MultiplicationConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let c = meta.advice_column();
+        let instance = meta.instance_column();
+
+        MultiplicationChip::configure(meta, [a, b, c], instance)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = MultiplicationChip::construct(config);
+
+        let a = chip.load_private(layouter.namespace(|| "load a"), self.a)?;
+        let b = chip.load_private(layouter.namespace(|| "load b"), self.b)?;
+
+        let c = chip.mul(layouter.namespace(|| "a * b"), a, b)?;
+
+        chip.expose_public(layouter.namespace(|| "expose c"), c, 0)
+    }
+}
+
+// Define a circuit computing c = a * k for a fixed constant k, to exercise
+// `load_constant`.
+#[derive(Default)]
+struct MultiplicationByConstantCircuit<F: FieldExt> {
+    a: Value<F>,
+    constant: F,
+}
+
+impl<F: FieldExt> Circuit<F> for MultiplicationByConstantCircuit<F> {
+    type Config = MultiplicationConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let c = meta.advice_column();
+        let instance = meta.instance_column();
+
+        MultiplicationChip::configure(meta, [a, b, c], instance)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = MultiplicationChip::construct(config);
+
+        let a = chip.load_private(layouter.namespace(|| "load a"), self.a)?;
+        let k = chip.load_constant(layouter.namespace(|| "load k"), self.constant)?;
+
+        let c = chip.mul(layouter.namespace(|| "a * k"), a, k)?;
+
+        chip.expose_public(layouter.namespace(|| "expose c"), c, 0)
+    }
+}
+
+// Composition chip wiring an AdditionChip and a MultiplicationChip together
+// through a single set of shared advice columns.
+struct FieldChip<F: FieldExt> {
+    config: FieldConfig,
+    _marker: PhantomData<F>,
+}
+
+#[derive(Clone, Debug)]
+struct FieldConfig {
+    advice: [Column<Advice>; 3],
+    instance: Column<Instance>,
+    add_config: AdditionConfig,
+    mul_config: MultiplicationConfig,
+}
+
+impl<F: FieldExt> FieldChip<F> {
+    fn construct(config: FieldConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    fn configure(
+        meta: &mut ConstraintSystem<F>,
+        advice: [Column<Advice>; 3],
+        instance: Column<Instance>,
+    ) -> FieldConfig {
+        let add_config = AdditionChip::configure(meta, advice);
+        let mul_config = MultiplicationChip::configure(meta, advice, instance);
+
+        FieldConfig {
+            advice,
+            instance,
+            add_config,
+            mul_config,
+        }
+    }
+}
+
+impl<F: FieldExt> Chip<F> for FieldChip<F> {
+    type Config = FieldConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<F: FieldExt> AddInstructions<F> for FieldChip<F> {
+    type Num = Number<F>;
+
+    fn add(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+    ) -> Result<Self::Num, Error> {
+        let chip = AdditionChip::construct(self.config.add_config.clone());
+        chip.add(layouter, a, b)
+    }
+}
+
+impl<F: FieldExt> MulInstructions<F> for FieldChip<F> {
+    type Num = Number<F>;
+
+    fn mul(
+        &self,
+        layouter: impl Layouter<F>,
+        a: Self::Num,
+        b: Self::Num,
+    ) -> Result<<Self as MulInstructions<F>>::Num, Error> {
+        let chip = MultiplicationChip::construct(self.config.mul_config.clone());
+        chip.mul(layouter, a, b)
+    }
+}
+
+impl<F: FieldExt> FieldInstructions<F> for FieldChip<F> {
+    type Num = Number<F>;
+
+    fn load_private(
+        &self,
+        mut layouter: impl Layouter<F>,
+        value: Value<F>,
+    ) -> Result<<Self as FieldInstructions<F>>::Num, Error> {
+        let config = self.config();
+
+        layouter.assign_region(
+            || "load private",
+            |mut region| {
+                region
+                    .assign_advice(|| "private input", config.advice[0], 0, || value)
+                    .map(Number)
+            },
+        )
+    }
+
+    fn expose_public(
+        &self,
+        mut layouter: impl Layouter<F>,
+        num: <Self as FieldInstructions<F>>::Num,
+        row: usize,
+    ) -> Result<(), Error> {
+        let config = self.config();
+
+        layouter.constrain_instance(num.0.cell(), config.instance, row)
+    }
+}
+
+/// Computes `d = (a + b) * c` by loading three private inputs and routing
+/// them through the addition chip and then the multiplication chip, so the
+/// sum produced in one region is copy-constrained into the next.
+fn add_and_mul<F: FieldExt, Chip: FieldInstructions<F>>(
+    chip: &Chip,
+    mut layouter: impl Layouter<F>,
+    a: Value<F>,
+    b: Value<F>,
+    c: Value<F>,
+) -> Result<<Chip as FieldInstructions<F>>::Num, Error> {
+    let a = chip.load_private(layouter.namespace(|| "load a"), a)?;
+    let b = chip.load_private(layouter.namespace(|| "load b"), b)?;
+    let c = chip.load_private(layouter.namespace(|| "load c"), c)?;
+
+    let sum = chip.add(layouter.namespace(|| "a + b"), a, b)?;
+    chip.mul(layouter.namespace(|| "(a + b) * c"), sum, c)
+}
+
+// Define the circuit computing d = (a + b) * c
+#[derive(Default)]
+struct AddAndMulCircuit<F: FieldExt> {
+    a: Value<F>,
+    b: Value<F>,
+    c: Value<F>,
+}
+
+impl<F: FieldExt> Circuit<F> for AddAndMulCircuit<F> {
+    type Config = FieldConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let c = meta.advice_column();
+        let instance = meta.instance_column();
+
+        FieldChip::configure(meta, [a, b, c], instance)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let chip = FieldChip::construct(config);
+
+        let d = add_and_mul(
+            &chip,
+            layouter.namespace(|| "(a + b) * c"),
+            self.a,
+            self.b,
+            self.c,
+        )?;
+
+        chip.expose_public(layouter.namespace(|| "expose d"), d, 0)
+    }
+}
+
+/// Instructions for element-wise multiplication of two equal-length vectors.
+trait VectorInstructions<F: FieldExt>: Chip<F> {
+    /// Variable representing a number.
+    type Num;
+
+    /// Loads a slice of values into the circuit as private inputs.
+    fn load_private(
+        &self,
+        layouter: impl Layouter<F>,
+        values: &[Value<F>],
+    ) -> Result<Vec<Self::Num>, Error>;
+
+    /// Returns the element-wise product `a_i * b_i`.
+    ///
+    /// The caller guarantees `a.len() == b.len()`.
+    fn mul(
+        &self,
+        layouter: impl Layouter<F>,
+        a: &[Self::Num],
+        b: &[Self::Num],
+    ) -> Result<Vec<Self::Num>, Error>;
+
+    /// Exposes a slice of numbers as public inputs, starting at `row`.
+    fn expose_public(
+        &self,
+        layouter: impl Layouter<F>,
+        nums: &[Self::Num],
+        row: usize,
+    ) -> Result<(), Error>;
+}
+
+// Define the chip for our element-wise vector multiplication circuit
+struct VectorMulChip<F: FieldExt> {
+    config: VectorMulConfig,
+    _marker: PhantomData<F>,
+}
+
+// Configuration for our vector multiplication chip
 #[derive(Clone, Debug)]
-struct MultiplicationConfig {
+struct VectorMulConfig {
     a: Column<Advice>,
     b: Column<Advice>,
     c: Column<Advice>,
-    selector: Selector,
+    s_mul: Selector,
     instance: Column<Instance>,
 }
 
-// Implementation of the multiplication chip
-impl<F: FieldExt> MultiplicationChip<F> {
-    fn construct(config: MultiplicationConfig) -> Self {
+// Implementation of the vector multiplication chip
+impl<F: FieldExt> VectorMulChip<F> {
+    fn construct(config: VectorMulConfig) -> Self {
         Self {
             config,
             _marker: PhantomData,
         }
     }
 
-    fn configure(meta: &mut ConstraintSystem<F>) -> MultiplicationConfig {
-        let a = meta.advice_column();
-        let b = meta.advice_column();
-        let c = meta.advice_column();
-        let selector = meta.selector();
-        let instance = meta.instance_column();
+    fn configure(
+        meta: &mut ConstraintSystem<F>,
+        advice: [Column<Advice>; 3],
+        instance: Column<Instance>,
+    ) -> VectorMulConfig {
+        let [a, b, c] = advice;
+        let s_mul = meta.selector();
 
         meta.enable_equality(a);
         meta.enable_equality(b);
         meta.enable_equality(c);
         meta.enable_equality(instance);
 
-        meta.create_gate("multiplication", |meta| {
-            let s = meta.query_selector(selector);
+        meta.create_gate("vector multiplication", |meta| {
+            let s = meta.query_selector(s_mul);
             let a = meta.query_advice(a, Rotation::cur());
             let b = meta.query_advice(b, Rotation::cur());
             let c = meta.query_advice(c, Rotation::cur());
             vec![s * (a * b - c)]
         });
 
-        MultiplicationConfig {
+        VectorMulConfig {
             a,
             b,
             c,
-            selector,
+            s_mul,
             instance,
         }
     }
+}
+
+impl<F: FieldExt> Chip<F> for VectorMulChip<F> {
+    type Config = VectorMulConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
 
-    fn assign(
+impl<F: FieldExt> VectorInstructions<F> for VectorMulChip<F> {
+    type Num = Number<F>;
+
+    fn load_private(
         &self,
         mut layouter: impl Layouter<F>,
-        a: F,
-        b: F,
-    ) -> Result<AssignedCell<F, F>, Error> {
+        values: &[Value<F>],
+    ) -> Result<Vec<Self::Num>, Error> {
+        let config = self.config();
+
         layouter.assign_region(
-            || "multiplication",
+            || "load private vector",
+            |mut region| {
+                values
+                    .iter()
+                    .enumerate()
+                    .map(|(i, value)| {
+                        region
+                            .assign_advice(|| format!("private input {i}"), config.a, i, || *value)
+                            .map(Number)
+                    })
+                    .collect()
+            },
+        )
+    }
+
+    fn mul(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &[Self::Num],
+        b: &[Self::Num],
+    ) -> Result<Vec<Self::Num>, Error> {
+        // The caller guarantees the two vectors line up element-wise.
+        assert_eq!(
+            a.len(),
+            b.len(),
+            "VectorMulChip::mul: a and b must have equal length"
+        );
+
+        let config = self.config();
+
+        layouter.assign_region(
+            || "vector multiplication",
             |mut region| {
-                self.config.selector.enable(&mut region, 0)?;
+                a.iter()
+                    .zip(b.iter())
+                    .enumerate()
+                    .map(|(i, (a_i, b_i))| {
+                        config.s_mul.enable(&mut region, i)?;
+
+                        a_i.0.copy_advice(|| "a", &mut region, config.a, i)?;
+                        b_i.0.copy_advice(|| "b", &mut region, config.b, i)?;
 
-                region.assign_advice(|| "a", self.config.a, 0, || Value::known(a))?;
-                region.assign_advice(|| "b", self.config.b, 0, || Value::known(b))?;
-                region.assign_advice(|| "c", self.config.c, 0, || Value::known(a * b))
+                        let value = a_i.0.value().copied() * b_i.0.value();
+
+                        region
+                            .assign_advice(|| "a * b", config.c, i, || value)
+                            .map(Number)
+                    })
+                    .collect()
             },
         )
     }
@@ -83,22 +747,28 @@ impl<F: FieldExt> MultiplicationChip<F> {
     fn expose_public(
         &self,
         mut layouter: impl Layouter<F>,
-        cell: AssignedCell<F, F>,
+        nums: &[Self::Num],
         row: usize,
     ) -> Result<(), Error> {
-        layouter.constrain_instance(cell.cell(), self.config.instance, row)
+        let config = self.config();
+
+        for (i, num) in nums.iter().enumerate() {
+            layouter.constrain_instance(num.0.cell(), config.instance, row + i)?;
+        }
+
+        Ok(())
     }
 }
 
 // Define our circuit
 #[derive(Default)]
-struct MultiplicationCircuit<F: FieldExt> {
-    a: F,
-    b: F,
+struct VectorMulCircuit<F: FieldExt> {
+    a: Vec<Value<F>>,
+    b: Vec<Value<F>>,
 }
 
-impl<F: FieldExt> Circuit<F> for MultiplicationCircuit<F> {
-    type Config = MultiplicationConfig;
+impl<F: FieldExt> Circuit<F> for VectorMulCircuit<F> {
+    type Config = VectorMulConfig;
     type FloorPlanner = SimpleFloorPlanner;
 
     fn without_witnesses(&self) -> Self {
@@ -106,7 +776,12 @@ impl<F: FieldExt> Circuit<F> for MultiplicationCircuit<F> {
     }
 
     fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
-        MultiplicationChip::configure(meta)
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let c = meta.advice_column();
+        let instance = meta.instance_column();
+
+        VectorMulChip::configure(meta, [a, b, c], instance)
     }
 
     fn synthesize(
@@ -114,22 +789,244 @@ impl<F: FieldExt> Circuit<F> for MultiplicationCircuit<F> {
         config: Self::Config,
         mut layouter: impl Layouter<F>,
     ) -> Result<(), Error> {
-        let chip = MultiplicationChip::construct(config);
+        // The caller guarantees `self.a.len() == self.b.len()`.
+        assert_eq!(self.a.len(), self.b.len());
 
-        let c = chip.assign(layouter.namespace(|| "assign multiplication"), self.a, self.b)?;
-        chip.expose_public(layouter.namespace(|| "expose c"), c, 0)?;
+        let chip = VectorMulChip::construct(config);
 
-        Ok(())
+        let a = chip.load_private(layouter.namespace(|| "load a"), &self.a)?;
+        let b = chip.load_private(layouter.namespace(|| "load b"), &self.b)?;
+
+        let c = chip.mul(layouter.namespace(|| "a .* b"), &a, &b)?;
+
+        chip.expose_public(layouter.namespace(|| "expose c"), &c, 0)
+    }
+}
+
+/// Instructions for constraining an already-assigned number to a bounded range.
+trait RangeInstructions<F: FieldExt>: Chip<F> {
+    /// Variable representing a number.
+    type Num;
+
+    /// Constrains `num` to lie in `[0, 2^n_bits)` via a lookup into the
+    /// chip's fixed range table.
+    fn range_check(
+        &self,
+        layouter: impl Layouter<F>,
+        num: Self::Num,
+        n_bits: usize,
+    ) -> Result<(), Error>;
+}
+
+// Define the chip for our range-check circuit
+struct RangeCheckChip<F: FieldExt> {
+    config: RangeCheckConfig,
+    _marker: PhantomData<F>,
+}
+
+// Configuration for our range-check chip
+#[derive(Clone, Debug)]
+struct RangeCheckConfig {
+    value: Column<Advice>,
+    q_range_check: Selector,
+    table: TableColumn,
+    n_bits: usize,
+}
+
+// Implementation of the range-check chip
+impl<F: FieldExt> RangeCheckChip<F> {
+    fn construct(config: RangeCheckConfig) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    fn configure(
+        meta: &mut ConstraintSystem<F>,
+        value: Column<Advice>,
+        n_bits: usize,
+    ) -> RangeCheckConfig {
+        let q_range_check = meta.complex_selector();
+        let table = meta.lookup_table_column();
+
+        meta.lookup(|meta| {
+            let q = meta.query_selector(q_range_check);
+            let value = meta.query_advice(value, Rotation::cur());
+            vec![(q * value, table)]
+        });
+
+        RangeCheckConfig {
+            value,
+            q_range_check,
+            table,
+            n_bits,
+        }
+    }
+
+    /// Populates the fixed table column with every value in `[0, 2^n_bits)`.
+    fn load_table(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
+        let config = self.config();
+
+        layouter.assign_table(
+            || "range check table",
+            |mut table| {
+                for value in 0..(1 << config.n_bits) {
+                    table.assign_cell(
+                        || "value",
+                        config.table,
+                        value,
+                        || Value::known(F::from(value as u64)),
+                    )?;
+                }
+
+                Ok(())
+            },
+        )
+    }
+}
+
+impl<F: FieldExt> Chip<F> for RangeCheckChip<F> {
+    type Config = RangeCheckConfig;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<F: FieldExt> RangeInstructions<F> for RangeCheckChip<F> {
+    type Num = Number<F>;
+
+    fn range_check(
+        &self,
+        mut layouter: impl Layouter<F>,
+        num: Self::Num,
+        n_bits: usize,
+    ) -> Result<(), Error> {
+        let config = self.config();
+        assert_eq!(
+            n_bits, config.n_bits,
+            "RangeCheckChip is configured for a different bit width"
+        );
+
+        layouter.assign_region(
+            || "range check",
+            |mut region| {
+                config.q_range_check.enable(&mut region, 0)?;
+                num.0
+                    .copy_advice(|| "value", &mut region, config.value, 0)?;
+
+                Ok(())
+            },
+        )
+    }
+}
+
+/// Number of bits the product `c` is constrained to in [`RangeCheckedMulCircuit`].
+const RANGE_CHECK_BITS: usize = 4;
+
+#[derive(Clone, Debug)]
+struct RangeCheckedMulConfig {
+    mul_config: MultiplicationConfig,
+    range_config: RangeCheckConfig,
+}
+
+// Define the circuit asserting that a * b fits in [0, 2^RANGE_CHECK_BITS)
+#[derive(Default)]
+struct RangeCheckedMulCircuit<F: FieldExt> {
+    a: Value<F>,
+    b: Value<F>,
+}
+
+impl<F: FieldExt> Circuit<F> for RangeCheckedMulCircuit<F> {
+    type Config = RangeCheckedMulConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let c = meta.advice_column();
+        let instance = meta.instance_column();
+
+        let mul_config = MultiplicationChip::configure(meta, [a, b, c], instance);
+        let range_config = RangeCheckChip::configure(meta, c, RANGE_CHECK_BITS);
+
+        RangeCheckedMulConfig {
+            mul_config,
+            range_config,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let mul_chip = MultiplicationChip::construct(config.mul_config);
+        let range_chip = RangeCheckChip::construct(config.range_config);
+
+        range_chip.load_table(&mut layouter)?;
+
+        let a = mul_chip.load_private(layouter.namespace(|| "load a"), self.a)?;
+        let b = mul_chip.load_private(layouter.namespace(|| "load b"), self.b)?;
+        let c = mul_chip.mul(layouter.namespace(|| "a * b"), a, b)?;
+
+        range_chip.range_check(
+            layouter.namespace(|| "range check c"),
+            c.clone(),
+            RANGE_CHECK_BITS,
+        )?;
+
+        mul_chip.expose_public(layouter.namespace(|| "expose c"), c, 0)
     }
 }
 
 fn main() {
-    // Run the test case
+    // Run the test cases
     let result = test_multiplication_circuit();
     match result {
         Ok(_) => println!("Test passed successfully!"),
         Err(e) => println!("Test failed: {:?}", e),
     }
+
+    let result = test_multiplication_by_constant_circuit();
+    match result {
+        Ok(_) => println!("Test passed successfully!"),
+        Err(e) => println!("Test failed: {:?}", e),
+    }
+
+    let result = test_add_and_mul_circuit();
+    match result {
+        Ok(_) => println!("Test passed successfully!"),
+        Err(e) => println!("Test failed: {:?}", e),
+    }
+
+    let result = test_vector_mul_circuit();
+    match result {
+        Ok(_) => println!("Test passed successfully!"),
+        Err(e) => println!("Test failed: {:?}", e),
+    }
+
+    let result = test_range_checked_mul_circuit();
+    match result {
+        Ok(_) => println!("Test passed successfully!"),
+        Err(e) => println!("Test failed: {:?}", e),
+    }
+
+    let result = test_prove_and_verify_multiplication();
+    match result {
+        Ok(proof) => println!("Generated and verified a {}-byte proof!", proof.len()),
+        Err(e) => println!("Test failed: {:?}", e),
+    }
 }
 
 fn test_multiplication_circuit() -> Result<(), Box<dyn std::error::Error>> {
@@ -141,7 +1038,10 @@ fn test_multiplication_circuit() -> Result<(), Box<dyn std::error::Error>> {
     let b = F::from(4);
     let c = a * b;
 
-    let circuit = MultiplicationCircuit { a, b };
+    let circuit = MultiplicationCircuit {
+        a: Value::known(a),
+        b: Value::known(b),
+    };
 
     // Set up the public input (instance)
     let public_inputs = vec![c];
@@ -155,12 +1055,192 @@ fn test_multiplication_circuit() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+fn test_multiplication_by_constant_circuit() -> Result<(), Box<dyn std::error::Error>> {
+    // Use Fp as our field
+    type F = Fp;
+
+    // Set up the circuit: c = a * k
+    let a = F::from(3);
+    let k = F::from(5);
+    let c = a * k;
+
+    let circuit = MultiplicationByConstantCircuit {
+        a: Value::known(a),
+        constant: k,
+    };
+
+    // Set up the public input (instance)
+    let public_inputs = vec![c];
+
+    // Run the mock prover
+    let prover = MockProver::run(4, &circuit, vec![public_inputs])?;
+
+    // Verify the circuit
+    prover.assert_satisfied();
+
+    Ok(())
+}
+
+fn test_prove_and_verify_multiplication() -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    // Use Fp as our field
+    type F = Fp;
+
+    let a = F::from(3);
+    let b = F::from(4);
+    let c = a * b;
+
+    let circuit = MultiplicationCircuit {
+        a: Value::known(a),
+        b: Value::known(b),
+    };
+
+    let proof = prove_and_verify::prove_and_verify(4, circuit, &[c])?;
+
+    Ok(proof)
+}
+
+fn test_add_and_mul_circuit() -> Result<(), Box<dyn std::error::Error>> {
+    // Use Fp as our field
+    type F = Fp;
+
+    // Set up the circuit: d = (a + b) * c
+    let a = F::from(2);
+    let b = F::from(3);
+    let c = F::from(4);
+    let d = (a + b) * c;
+
+    let circuit = AddAndMulCircuit {
+        a: Value::known(a),
+        b: Value::known(b),
+        c: Value::known(c),
+    };
+
+    // Set up the public input (instance)
+    let public_inputs = vec![d];
+
+    // Run the mock prover
+    let prover = MockProver::run(4, &circuit, vec![public_inputs])?;
+
+    // Verify the circuit
+    prover.assert_satisfied();
+
+    Ok(())
+}
+
+fn test_vector_mul_circuit() -> Result<(), Box<dyn std::error::Error>> {
+    // Use Fp as our field
+    type F = Fp;
+
+    // Set up the circuit: c_i = a_i * b_i
+    let a = vec![F::from(1), F::from(2), F::from(3), F::from(4)];
+    let b = vec![F::from(5), F::from(6), F::from(7), F::from(8)];
+    let c: Vec<F> = a
+        .iter()
+        .zip(b.iter())
+        .map(|(a_i, b_i)| *a_i * b_i)
+        .collect();
+
+    let circuit = VectorMulCircuit {
+        a: a.into_iter().map(Value::known).collect(),
+        b: b.into_iter().map(Value::known).collect(),
+    };
+
+    // Set up the public input (instance), one row per vector element
+    let public_inputs = c;
+
+    // Run the mock prover with enough rows for the vector length
+    let prover = MockProver::run(5, &circuit, vec![public_inputs])?;
+
+    // Verify the circuit
+    prover.assert_satisfied();
+
+    Ok(())
+}
+
+fn run_range_checked_mul_circuit<F: FieldExt>(
+    a: F,
+    b: F,
+) -> Result<(), Vec<halo2_proofs::dev::VerifyFailure>> {
+    let c = a * b;
+
+    let circuit = RangeCheckedMulCircuit {
+        a: Value::known(a),
+        b: Value::known(b),
+    };
+
+    let public_inputs = vec![c];
+
+    let prover = MockProver::run(5, &circuit, vec![public_inputs]).unwrap();
+    prover.verify()
+}
+
+fn test_range_checked_mul_circuit() -> Result<(), Box<dyn std::error::Error>> {
+    // Use Fp as our field
+    type F = Fp;
+
+    // 3 * 4 = 12, which fits in [0, 2^RANGE_CHECK_BITS) = [0, 16)
+    run_range_checked_mul_circuit::<F>(F::from(3), F::from(4))
+        .map_err(|failures| format!("{:?}", failures).into())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use halo2_proofs::dev::VerifyFailure;
 
     #[test]
     fn test_multiplication() {
         assert!(test_multiplication_circuit().is_ok());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_multiplication_by_constant() {
+        assert!(test_multiplication_by_constant_circuit().is_ok());
+    }
+
+    #[test]
+    fn test_add_and_mul() {
+        assert!(test_add_and_mul_circuit().is_ok());
+    }
+
+    #[test]
+    fn test_vector_mul() {
+        assert!(test_vector_mul_circuit().is_ok());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vector_mul_length_mismatch() {
+        // The caller guarantees equal-length inputs; feeding unequal lengths
+        // should trip the assertion in `VectorMulCircuit::synthesize`.
+        let circuit = VectorMulCircuit {
+            a: vec![Value::known(Fp::from(1)), Value::known(Fp::from(2))],
+            b: vec![Value::known(Fp::from(3))],
+        };
+
+        let _ = MockProver::run(4, &circuit, vec![vec![Fp::from(3)]]);
+    }
+
+    #[test]
+    fn test_range_check_in_range() {
+        // 3 * 4 = 12 fits in [0, 16)
+        assert!(run_range_checked_mul_circuit::<Fp>(Fp::from(3), Fp::from(4)).is_ok());
+    }
+
+    #[test]
+    fn test_range_check_out_of_range() {
+        // 5 * 5 = 25 does not fit in [0, 16)
+        let result = run_range_checked_mul_circuit::<Fp>(Fp::from(5), Fp::from(5));
+        match result {
+            Err(failures) => assert!(failures
+                .iter()
+                .any(|failure| matches!(failure, VerifyFailure::Lookup { .. }))),
+            Ok(()) => panic!("expected a range-check lookup failure"),
+        }
+    }
+
+    #[test]
+    fn test_prove_and_verify() {
+        assert!(test_prove_and_verify_multiplication().is_ok());
+    }
+}
@@ -0,0 +1,58 @@
+//! A real proving/verifying pipeline, as opposed to `MockProver`.
+//!
+//! `MockProver` only checks that a circuit's constraints are satisfied; it
+//! never produces a proof. This module runs the full IPA/Pasta pipeline
+//! (`keygen_vk` + `keygen_pk` + `create_proof` + `verify_proof`) so callers
+//! end up with proof bytes they can actually transmit and check elsewhere.
+
+use halo2_proofs::{
+    pasta::{EqAffine, Fp},
+    plonk::{create_proof, keygen_pk, keygen_vk, verify_proof, Circuit, Error, SingleVerifier},
+    poly::commitment::Params,
+    transcript::{Blake2bRead, Blake2bWrite, Challenge255},
+};
+use rand_core::OsRng;
+
+/// Generates keys for `circuit`, proves `public_inputs` against it, and
+/// verifies the resulting proof, returning the serialized proof bytes.
+///
+/// Key generation runs against `circuit.without_witnesses()`, so the
+/// circuit's fields must be `Value::unknown()`-friendly (see
+/// `Circuit::without_witnesses`) independently of the witnesses used to
+/// create the proof.
+pub fn prove_and_verify<C: Circuit<Fp>>(
+    k: u32,
+    circuit: C,
+    public_inputs: &[Fp],
+) -> Result<Vec<u8>, Error> {
+    let params: Params<EqAffine> = Params::new(k);
+
+    let empty_circuit = circuit.without_witnesses();
+    let vk = keygen_vk(&params, &empty_circuit)?;
+    let pk = keygen_pk(&params, vk, &empty_circuit)?;
+
+    let instances: &[&[Fp]] = &[public_inputs];
+
+    let mut transcript = Blake2bWrite::<_, EqAffine, Challenge255<_>>::init(vec![]);
+    create_proof(
+        &params,
+        &pk,
+        &[circuit],
+        &[instances],
+        OsRng,
+        &mut transcript,
+    )?;
+    let proof = transcript.finalize();
+
+    let strategy = SingleVerifier::new(&params);
+    let mut transcript = Blake2bRead::<_, EqAffine, Challenge255<_>>::init(&proof[..]);
+    verify_proof(
+        &params,
+        pk.get_vk(),
+        strategy,
+        &[instances],
+        &mut transcript,
+    )?;
+
+    Ok(proof)
+}